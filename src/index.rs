@@ -0,0 +1,214 @@
+use std::borrow::Cow;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use flatbuffers::FlatBufferBuilder;
+use typed_builder::TypedBuilder;
+
+use crate::generated::{
+    Index, IndexArgs, Package, PackageArgs, Release, ReleaseArgs, Target, TargetArgs,
+};
+use crate::package::update::{describe_toml_error, find_repo, FindRepoError};
+
+#[non_exhaustive]
+#[derive(Debug, Clone, TypedBuilder)]
+pub struct Request<'a> {
+    pub repo_path: Cow<'a, Path>,
+    pub output_path: Cow<'a, Path>,
+}
+
+#[non_exhaustive]
+#[derive(Debug, Clone, Default, TypedBuilder)]
+pub struct PartialRequest<'a> {
+    #[builder(default)]
+    pub repo_path: Option<&'a Path>,
+    #[builder(default)]
+    pub output_path: Option<&'a Path>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum RequestError {
+    #[error("Provided path was invalid")]
+    PathError(#[source] io::Error),
+
+    #[error("Could not find repository at provided path")]
+    NoRepo(#[from] FindRepoError),
+
+    #[error("Invalid input")]
+    InvalidInput,
+}
+
+impl<'a> crate::Request for Request<'a> {
+    type Error = RequestError;
+    type Partial = PartialRequest<'a>;
+
+    fn new_from_user_input(partial: Self::Partial) -> Result<Self, Self::Error> {
+        use dialoguer::Input;
+
+        let repo_path = match partial.repo_path {
+            Some(path) => Cow::Borrowed(path),
+            None => Input::<String>::new()
+                .default(
+                    std::env::current_dir()
+                        .ok()
+                        .and_then(|x| x.to_str().map(str::to_string))
+                        .unwrap_or_else(|| ".".into()),
+                )
+                .with_prompt("Repository Path")
+                .interact()
+                .map(|p| Cow::Owned(PathBuf::from(p)))
+                .map_err(RequestError::PathError)?,
+        };
+
+        let repo_path = Cow::Owned(find_repo(&repo_path)?.to_path_buf());
+
+        let output_path = match partial.output_path {
+            Some(path) => Cow::Borrowed(path),
+            None => Input::<String>::new()
+                .with_prompt("Output path for binary index")
+                .default(
+                    repo_path
+                        .join("index.bin")
+                        .to_str()
+                        .unwrap_or("index.bin")
+                        .to_string(),
+                )
+                .interact()
+                .map(|p| Cow::Owned(PathBuf::from(p)))
+                .map_err(|_| RequestError::InvalidInput)?,
+        };
+
+        Ok(Request {
+            repo_path,
+            output_path,
+        })
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("Could not read packages directory `{0}`")]
+    ReadPackagesDir(PathBuf, #[source] io::Error),
+
+    #[error("Could not read package descriptor `{0}`")]
+    Io(PathBuf, #[source] io::Error),
+
+    #[error("{0}")]
+    DescriptorToml(String),
+
+    #[error("Failed to serialize target payload for package `{0}`")]
+    SerializeDescriptor(String, #[source] toml::ser::Error),
+
+    #[error("Failed to write binary index `{0}`")]
+    WriteIndex(PathBuf, #[source] io::Error),
+}
+
+/// Packs every `packages/*/index.toml` descriptor in the repository at
+/// `request.repo_path` into the FlatBuffers layout defined by
+/// `pahkat_types::FLATBUFFERS_INDEX`, writing the result to `request.output_path`.
+///
+/// Each package's id, releases, and targets are mapped onto their own
+/// FlatBuffers tables (mirroring `pahkat_types::package::Release` and
+/// `pahkat_types::payload::Target`)
+/// rather than embedded as a single opaque TOML blob, so clients can read
+/// version/channel/platform straight out of the binary index without
+/// re-parsing TOML for every package; only each target's `payload` — a
+/// polymorphic, installer-specific shape — stays TOML-encoded.
+pub fn build_index<'a>(request: Request<'a>) -> anyhow::Result<()> {
+    // `repo_path` is already the repo root: `new_from_user_input` resolved it
+    // via `find_repo` once, so there's no need to walk parents again here.
+    let repo_path: &Path = &request.repo_path;
+    let packages_dir = repo_path.join("packages");
+
+    let mut descriptors: Vec<(String, pahkat_types::package::Descriptor)> = Vec::new();
+
+    let entries = fs::read_dir(&packages_dir)
+        .map_err(|e| Error::ReadPackagesDir(packages_dir.clone(), e))?;
+    for entry in entries {
+        let entry = entry.map_err(|e| Error::ReadPackagesDir(packages_dir.clone(), e))?;
+
+        let id = match entry.file_name().into_string() {
+            Ok(id) => id,
+            Err(_) => continue,
+        };
+
+        let pkg_path = entry.path().join("index.toml");
+        if !pkg_path.is_file() {
+            continue;
+        }
+
+        let raw = fs::read_to_string(&pkg_path).map_err(|e| Error::Io(pkg_path.clone(), e))?;
+        let descriptor: pahkat_types::package::Descriptor = toml::from_str(&raw)
+            .map_err(|e| Error::DescriptorToml(describe_toml_error(&pkg_path, &e)))?;
+
+        descriptors.push((id, descriptor));
+    }
+
+    let mut builder = FlatBufferBuilder::new();
+    let mut package_offsets = Vec::with_capacity(descriptors.len());
+
+    // Children are built before their parents, so this walks descriptor ->
+    // release -> target depth-first rather than via iterator chains, which
+    // would need several closures fighting over one `&mut builder`.
+    for (id, descriptor) in &descriptors {
+        let mut release_offsets = Vec::with_capacity(descriptor.release.len());
+
+        for release in &descriptor.release {
+            let mut target_offsets = Vec::with_capacity(release.target.len());
+
+            for target in &release.target {
+                let platform = builder.create_string(&target.platform);
+                let payload = toml::to_string(&target.payload)
+                    .map_err(|e| Error::SerializeDescriptor(id.clone(), e))?;
+                let payload = builder.create_string(&payload);
+
+                target_offsets.push(Target::create(
+                    &mut builder,
+                    &TargetArgs {
+                        platform: Some(platform),
+                        payload: Some(payload),
+                    },
+                ));
+            }
+
+            let target = builder.create_vector(&target_offsets);
+            let version = builder.create_string(&release.version.to_string());
+            let channel = release.channel.as_deref().map(|c| builder.create_string(c));
+
+            release_offsets.push(Release::create(
+                &mut builder,
+                &ReleaseArgs {
+                    version: Some(version),
+                    channel,
+                    target: Some(target),
+                },
+            ));
+        }
+
+        let release = builder.create_vector(&release_offsets);
+        let id = builder.create_string(id);
+
+        package_offsets.push(Package::create(
+            &mut builder,
+            &PackageArgs {
+                id: Some(id),
+                release: Some(release),
+            },
+        ));
+    }
+
+    let packages = builder.create_vector(&package_offsets);
+    let index = Index::create(
+        &mut builder,
+        &IndexArgs {
+            packages: Some(packages),
+        },
+    );
+    builder.finish(index, None);
+
+    fs::write(&*request.output_path, builder.finished_data())
+        .map_err(|e| Error::WriteIndex(request.output_path.to_path_buf(), e))?;
+
+    Ok(())
+}
@@ -0,0 +1,16 @@
+pub mod index;
+pub mod package;
+
+#[allow(dead_code, unused_imports)]
+pub(crate) mod generated {
+    include!(concat!(env!("OUT_DIR"), "/index.rs"));
+}
+
+pub trait Request {
+    type Error;
+    type Partial;
+
+    fn new_from_user_input(partial: Self::Partial) -> Result<Self, Self::Error>
+    where
+        Self: Sized;
+}
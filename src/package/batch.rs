@@ -0,0 +1,112 @@
+use std::borrow::Cow;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+use pahkat_types::package::Version;
+
+use super::update::{describe_toml_error, find_repo, update, FindRepoError, Request as UpdateRequest};
+
+/// A single update entry within a [`Manifest`], mirroring the fields a
+/// maintainer would otherwise be prompted for one at a time.
+#[derive(Debug, Deserialize)]
+pub struct ManifestEntry {
+    pub id: String,
+    pub platform: String,
+    pub channel: Option<String>,
+    pub version: Version,
+    pub payload_path: PathBuf,
+}
+
+/// A batch of [`ManifestEntry`] values describing every package/platform
+/// update to apply in a single, non-interactive pass.
+#[derive(Debug, Deserialize)]
+pub struct Manifest {
+    pub entry: Vec<ManifestEntry>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("Could not find repository at provided path")]
+    NoRepo(#[from] FindRepoError),
+
+    #[error("Could not read batch manifest `{0}`")]
+    Io(PathBuf, #[source] io::Error),
+
+    #[error("{0}")]
+    ManifestToml(String),
+
+    #[error("Could not read payload `{0}`")]
+    PayloadIo(PathBuf, #[source] io::Error),
+
+    #[error("{0}")]
+    PayloadToml(String),
+
+    #[error("No package `{0}` found at `{1}`")]
+    UnknownPackage(String, PathBuf),
+
+    #[error("{0} of {1} entries failed to resolve:\n{2}")]
+    UnresolvedEntries(usize, usize, String),
+}
+
+fn resolve_entry<'a>(
+    repo_path: &'a Path,
+    entry: &'a ManifestEntry,
+) -> Result<UpdateRequest<'a>, Error> {
+    let pkg_path = repo_path.join("packages").join(&entry.id).join("index.toml");
+    if !pkg_path.is_file() {
+        return Err(Error::UnknownPackage(entry.id.clone(), pkg_path));
+    }
+
+    let raw = fs::read_to_string(&entry.payload_path)
+        .map_err(|e| Error::PayloadIo(entry.payload_path.clone(), e))?;
+    let payload: pahkat_types::payload::Payload = toml::from_str(&raw)
+        .map_err(|e| Error::PayloadToml(describe_toml_error(&entry.payload_path, &e)))?;
+
+    Ok(UpdateRequest::builder()
+        .repo_path(Cow::Borrowed(repo_path))
+        .id(Cow::Borrowed(entry.id.as_str()))
+        .platform(Cow::Borrowed(entry.platform.as_str()))
+        .channel(entry.channel.as_deref().map(Cow::Borrowed))
+        .version(Cow::Borrowed(&entry.version))
+        .payload(Cow::Owned(payload))
+        .build())
+}
+
+/// Applies every entry of the manifest at `manifest_path` in one pass,
+/// without any interactive prompts. Resolution (locating the repo, checking
+/// each entry's `packages/<id>/index.toml` exists, reading its payload TOML)
+/// happens for the whole manifest up front; if any entry fails to resolve —
+/// including a typo'd `id` with no matching package — the batch short-circuits
+/// with an aggregated error describing every failure instead of applying a
+/// partial set of updates.
+pub fn batch_update(repo_path: &Path, manifest_path: &Path) -> anyhow::Result<()> {
+    let repo_path = find_repo(repo_path)?;
+
+    let raw = fs::read_to_string(manifest_path).map_err(|e| Error::Io(manifest_path.to_path_buf(), e))?;
+    let manifest: Manifest = toml::from_str(&raw)
+        .map_err(|e| Error::ManifestToml(describe_toml_error(manifest_path, &e)))?;
+
+    let total = manifest.entry.len();
+    let mut requests = Vec::with_capacity(total);
+    let mut failures = Vec::new();
+
+    for entry in &manifest.entry {
+        match resolve_entry(repo_path, entry) {
+            Ok(request) => requests.push(request),
+            Err(e) => failures.push(format!("{}/{} ({:?}): {}", entry.id, entry.platform, entry.version, e)),
+        }
+    }
+
+    if !failures.is_empty() {
+        return Err(Error::UnresolvedEntries(failures.len(), total, failures.join("\n")).into());
+    }
+
+    for request in requests {
+        update(request)?;
+    }
+
+    Ok(())
+}
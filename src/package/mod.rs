@@ -0,0 +1,3 @@
+pub mod batch;
+pub mod update;
+pub mod yank;
@@ -45,8 +45,8 @@ pub enum RequestError {
     #[error("Could not read payload TOML file")]
     Io(#[from] std::io::Error),
 
-    #[error("Could not read payload TOML file")]
-    PayloadToml(#[from] toml::de::Error),
+    #[error("{0}")]
+    PayloadToml(String),
 
     #[error("Invalid input")]
     InvalidInput,
@@ -61,13 +61,48 @@ pub enum FindRepoError {
     NotFound,
 }
 
+/// Formats a TOML decode error as `path:line:col: message`. `toml::de::Error`
+/// doesn't always carry a location (e.g. some I/O-adjacent failures), in
+/// which case this falls back to `path: message` rather than lying about
+/// line 1, column 1.
+pub(crate) fn describe_toml_error(path: &Path, err: &toml::de::Error) -> String {
+    match err.line_col() {
+        Some((line, col)) => format!("{}:{}:{}: {}", path.display(), line + 1, col + 1, err),
+        None => format!("{}: {}", path.display(), err),
+    }
+}
+
+/// If `cwd` is inside `<repo_root>/packages/<id>/`, returns that `<id>` so it
+/// can prefill the package-identifier prompt, mirroring how `find_repo`
+/// already walks parents to locate the repo itself.
+pub(crate) fn detect_package_id(repo_root: &Path, cwd: &Path) -> Option<String> {
+    let packages_dir = repo_root.join("packages");
+    let rel = cwd.strip_prefix(&packages_dir).ok()?;
+    let id = rel.components().next()?.as_os_str().to_str()?.to_string();
+    Some(id)
+}
+
+/// Returns the platform of `id`'s most recent release when it has exactly one
+/// `Target`, so the platform prompt can default to the unambiguous choice.
+pub(crate) fn detect_unambiguous_platform(repo_root: &Path, id: &str) -> Option<String> {
+    let pkg_path = repo_root.join("packages").join(id).join("index.toml");
+    let raw = fs::read_to_string(pkg_path).ok()?;
+    let descriptor: pahkat_types::package::Descriptor = toml::from_str(&raw).ok()?;
+    let release = descriptor.release.first()?;
+
+    match release.target.as_slice() {
+        [target] => Some(target.platform.clone()),
+        _ => None,
+    }
+}
+
 fn open_repo(path: &Path) -> Option<pahkat_types::repo::Repository> {
     let file = fs::read_to_string(path.join("index.toml")).ok()?;
     let repo: pahkat_types::repo::Repository = toml::from_str(&file).ok()?;
     Some(repo)
 }
 
-fn find_repo(path: &Path) -> Result<&Path, FindRepoError> {
+pub(crate) fn find_repo(path: &Path) -> Result<&Path, FindRepoError> {
     let mut path = path;
 
     if path.ends_with("index.toml") {
@@ -110,16 +145,21 @@ impl<'a> crate::Request for Request<'a> {
                 .map_err(RequestError::PathError)?,
         };
 
-        let _ = find_repo(&repo_path)?;
+        let repo_root = find_repo(&repo_path)?;
+
+        let detected_id = std::env::current_dir()
+            .ok()
+            .and_then(|cwd| detect_package_id(repo_root, &cwd));
 
         let id = match partial.id {
             Some(id) => Cow::Borrowed(id),
-            None => Cow::Owned(
-                Input::<String>::new()
-                    .with_prompt("Package identifier")
-                    .interact()
-                    .map_err(|_| RequestError::InvalidInput)?,
-            ),
+            None => {
+                let mut input = Input::<String>::new().with_prompt("Package identifier");
+                if let Some(ref detected_id) = detected_id {
+                    input = input.default(detected_id.clone());
+                }
+                Cow::Owned(input.interact().map_err(|_| RequestError::InvalidInput)?)
+            }
         };
 
         let payload_path = match partial.payload_path {
@@ -133,8 +173,9 @@ impl<'a> crate::Request for Request<'a> {
             ),
         };
 
-        let payload = std::fs::read_to_string(payload_path)?;
-        let payload: pahkat_types::payload::Payload = toml::from_str(&payload)?;
+        let payload = std::fs::read_to_string(&payload_path)?;
+        let payload: pahkat_types::payload::Payload = toml::from_str(&payload)
+            .map_err(|e| RequestError::PayloadToml(describe_toml_error(&payload_path, &e)))?;
 
         let channel = match partial.channel {
             Some(channel) => Some(Cow::Borrowed(channel)),
@@ -149,14 +190,17 @@ impl<'a> crate::Request for Request<'a> {
                 })?
         };
 
+        let detected_platform = detect_unambiguous_platform(repo_root, &id);
+
         let platform = match partial.platform {
             Some(name) => Cow::Borrowed(name),
-            None => Cow::Owned(
-                Input::<String>::new()
-                    .with_prompt("Platform")
-                    .interact()
-                    .map_err(|_| RequestError::InvalidInput)?,
-            ),
+            None => {
+                let mut input = Input::<String>::new().with_prompt("Platform");
+                if let Some(ref detected_platform) = detected_platform {
+                    input = input.default(detected_platform.clone());
+                }
+                Cow::Owned(input.interact().map_err(|_| RequestError::InvalidInput)?)
+            }
         };
 
         let version = match partial.version {
@@ -193,11 +237,13 @@ pub enum Error {
 
     #[error("Could not find repository at provided path")]
     NoRepo(#[from] FindRepoError),
+
+    #[error("{0}")]
+    DescriptorToml(String),
 }
 
 pub fn update<'a>(request: Request<'a>) -> anyhow::Result<()> {
     use std::ops::Deref;
-    println!("{:?}", request);
 
     let pkg_dir = find_repo(&request.repo_path)?
         .join("packages")
@@ -205,7 +251,8 @@ pub fn update<'a>(request: Request<'a>) -> anyhow::Result<()> {
 
     let pkg_path = pkg_dir.join("index.toml");
     let pkg_file = std::fs::read_to_string(&pkg_path)?;
-    let mut descriptor: pahkat_types::package::Descriptor = toml::from_str(&pkg_file)?;
+    let mut descriptor: pahkat_types::package::Descriptor = toml::from_str(&pkg_file)
+        .map_err(|e| Error::DescriptorToml(describe_toml_error(&pkg_path, &e)))?;
 
     let channel = request.channel.as_ref().map(|x| x.deref().to_string());
 
@@ -0,0 +1,217 @@
+use std::borrow::Cow;
+use std::fs;
+use std::io;
+use std::ops::Deref;
+use std::path::{Path, PathBuf};
+
+use pahkat_types::package::Version;
+use typed_builder::TypedBuilder;
+
+use super::update::{
+    describe_toml_error, detect_package_id, detect_unambiguous_platform, find_repo, FindRepoError,
+};
+
+#[non_exhaustive]
+#[derive(Debug, Clone, TypedBuilder)]
+pub struct Request<'a> {
+    pub repo_path: Cow<'a, Path>,
+    pub id: Cow<'a, str>,
+    pub platform: Option<Cow<'a, str>>,
+    pub channel: Option<Cow<'a, str>>,
+    pub version: Cow<'a, Version>,
+}
+
+#[non_exhaustive]
+#[derive(Debug, Clone, Default, TypedBuilder)]
+pub struct PartialRequest<'a> {
+    #[builder(default)]
+    pub repo_path: Option<&'a Path>,
+    #[builder(default)]
+    pub id: Option<&'a str>,
+    #[builder(default)]
+    pub platform: Option<&'a str>,
+    #[builder(default)]
+    pub channel: Option<&'a str>,
+    #[builder(default)]
+    pub version: Option<&'a Version>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum RequestError {
+    #[error("Provided path was invalid")]
+    PathError(#[source] io::Error),
+
+    #[error("Could not find repository at provided path")]
+    NoRepo(#[from] FindRepoError),
+
+    #[error("Invalid input")]
+    InvalidInput,
+}
+
+impl<'a> crate::Request for Request<'a> {
+    type Error = RequestError;
+    type Partial = PartialRequest<'a>;
+
+    fn new_from_user_input(partial: Self::Partial) -> Result<Self, Self::Error> {
+        use dialoguer::Input;
+
+        let repo_path = match partial.repo_path {
+            Some(path) => Cow::Borrowed(path),
+            None => Input::<String>::new()
+                .default(
+                    std::env::current_dir()
+                        .ok()
+                        .and_then(|x| x.to_str().map(str::to_string))
+                        .unwrap_or_else(|| ".".into()),
+                )
+                .with_prompt("Repository Path")
+                .interact()
+                .map(|p| Cow::Owned(PathBuf::from(p)))
+                .map_err(RequestError::PathError)?,
+        };
+
+        let repo_root = find_repo(&repo_path)?;
+
+        let detected_id = std::env::current_dir()
+            .ok()
+            .and_then(|cwd| detect_package_id(repo_root, &cwd));
+
+        let id = match partial.id {
+            Some(id) => Cow::Borrowed(id),
+            None => {
+                let mut input = Input::<String>::new().with_prompt("Package identifier");
+                if let Some(ref detected_id) = detected_id {
+                    input = input.default(detected_id.clone());
+                }
+                Cow::Owned(input.interact().map_err(|_| RequestError::InvalidInput)?)
+            }
+        };
+
+        let channel = match partial.channel {
+            Some(channel) => Some(Cow::Borrowed(channel)),
+            None => Input::<String>::new()
+                .with_prompt("Channel (or none for stable)")
+                .interact()
+                .map_err(|_| RequestError::InvalidInput)
+                .map(|v| if v == "" { None } else { Some(Cow::Owned(v)) })?,
+        };
+
+        let detected_platform = detect_unambiguous_platform(repo_root, &id);
+
+        let platform = match partial.platform {
+            Some(platform) => Some(Cow::Borrowed(platform)),
+            None => {
+                let mut input =
+                    Input::<String>::new().with_prompt("Platform (or none to yank the whole release)");
+                if let Some(ref detected_platform) = detected_platform {
+                    input = input.default(detected_platform.clone());
+                }
+                input
+                    .interact()
+                    .map_err(|_| RequestError::InvalidInput)
+                    .map(|v| if v == "" { None } else { Some(Cow::Owned(v)) })?
+            }
+        };
+
+        let version = match partial.version {
+            Some(version) => Cow::Borrowed(version),
+            None => Cow::Owned(
+                Input::<Version>::new()
+                    .with_prompt("Release version to yank")
+                    .interact()
+                    .map_err(|_| RequestError::InvalidInput)?,
+            ),
+        };
+
+        Ok(Request {
+            repo_path,
+            id,
+            channel,
+            platform,
+            version,
+        })
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("Failed to write TOML file `{0}`")]
+    WriteToml(PathBuf, #[source] io::Error),
+
+    #[error("Failed to serialize TOML for `{0}`")]
+    SerializeToml(PathBuf, #[source] toml::ser::Error),
+
+    #[error("Could not find repository at provided path")]
+    NoRepo(#[from] FindRepoError),
+
+    #[error("{0}")]
+    DescriptorToml(String),
+
+    #[error("No release matching version `{version:?}` on channel `{channel}` was found for package `{id}`")]
+    ReleaseNotFound {
+        id: String,
+        version: Version,
+        channel: String,
+    },
+
+    #[error("No target for platform `{platform}` was found in release `{version:?}` of package `{id}`")]
+    TargetNotFound {
+        id: String,
+        version: Version,
+        platform: String,
+    },
+}
+
+/// Retires a release or a single target of a release, mirroring Cargo's `yank`:
+/// the release entry stays in `index.toml`'s history but clients stop being
+/// offered it (either one `Target`, or all of them when no platform is given).
+pub fn yank<'a>(request: Request<'a>) -> anyhow::Result<()> {
+    let pkg_dir = find_repo(&request.repo_path)?
+        .join("packages")
+        .join(&*request.id);
+
+    let pkg_path = pkg_dir.join("index.toml");
+    let pkg_file = std::fs::read_to_string(&pkg_path)?;
+    let mut descriptor: pahkat_types::package::Descriptor = toml::from_str(&pkg_file)
+        .map_err(|e| Error::DescriptorToml(describe_toml_error(&pkg_path, &e)))?;
+
+    let channel = request.channel.as_ref().map(|x| x.deref().to_string());
+
+    let release = descriptor
+        .release
+        .iter_mut()
+        .find(|x| &x.version == &*request.version && x.channel == channel)
+        .ok_or_else(|| Error::ReleaseNotFound {
+            id: request.id.to_string(),
+            version: request.version.deref().clone(),
+            channel: channel.clone().unwrap_or_else(|| "stable".to_string()),
+        })?;
+
+    match request.platform.as_deref() {
+        Some(platform) => {
+            let index = release
+                .target
+                .iter()
+                .position(|x| x.platform == platform)
+                .ok_or_else(|| Error::TargetNotFound {
+                    id: request.id.to_string(),
+                    version: request.version.deref().clone(),
+                    platform: platform.to_string(),
+                })?;
+            release.target.remove(index);
+        }
+        None => {
+            // `Release` has no dedicated "yanked" flag; clearing its targets
+            // means no client is offered anything for it while the release
+            // entry (and its version/channel history) stays in the TOML.
+            release.target.clear();
+        }
+    }
+
+    // Write the toml
+    let data =
+        toml::to_string_pretty(&descriptor).map_err(|e| Error::SerializeToml(pkg_path.clone(), e))?;
+    fs::write(&pkg_path, data).map_err(|e| Error::WriteToml(pkg_path.to_path_buf(), e))?;
+
+    Ok(())
+}